@@ -0,0 +1,99 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use super::rules::SERIES_NUMBER_RANGE;
+
+/// Exact odds-and-expectation for a validated guess, computed analytically
+/// instead of by brute-force simulation.
+///
+/// The draw picks `k` distinct numbers from the [`SERIES_NUMBER_RANGE`] plus one
+/// Superzahl (drawn from the same range but excluded from the series). A win
+/// requires every drawn series number to be contained in the user's `k`-number
+/// set and the Superzahl to match exactly. Since the user's set has exactly `k`
+/// members, the draw must reproduce that set: probability `1 / C(49, k)`. The
+/// Superzahl then matches with probability `1 / (49 - k)`.
+pub struct Analysis {
+   series_len: usize,
+   // C(49, k) * (49 - k): the reciprocal of the win probability, i.e. the
+   // expected number of draws of a geometric distribution with mean 1/p.
+   denominator: u128,
+}
+
+impl Analysis {
+   pub fn new(series_len: usize) -> Self {
+      // Range size, e.g. 49 for 1..=49.
+      let n = (SERIES_NUMBER_RANGE.end() - SERIES_NUMBER_RANGE.start() + 1) as u128;
+      let k = series_len as u128;
+      let denominator = binomial(n, k) * (n - k);
+
+      Self { series_len, denominator }
+   }
+
+   /// The exact win probability per draw.
+   pub fn probability(&self) -> f64 {
+      1.0 / self.denominator as f64
+   }
+
+   /// The expected number of draws until a win (geometric-distribution mean).
+   pub fn expected_tries(&self) -> u128 {
+      self.denominator
+   }
+}
+
+impl Display for Analysis {
+   fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+      writeln!(formatter, "{}", "~".repeat(60))?;
+      writeln!(formatter, "🎯 Exact odds for a {}-number guess", self.series_len)?;
+      writeln!(formatter, "probability: 1 / {} = {:e}", self.denominator, self.probability())?;
+      write!(formatter, "expected draws until win: {}", self.expected_tries())?;
+      write!(formatter, "\n{}", "~".repeat(60))
+   }
+}
+
+/// Integer binomial coefficient C(n, k) via the multiplicative recurrence
+/// C(n, k) = C(n, k-1) * (n - k + 1) / k, carried in `u128` to avoid the
+/// overflow a naive factorial would hit.
+fn binomial(n: u128, k: u128) -> u128 {
+   if k > n {
+      return 0;
+   }
+
+   // Exploit symmetry C(n, k) == C(n, n - k) to keep the loop short.
+   let k = k.min(n - k);
+
+   let mut result: u128 = 1;
+   for i in 1..=k {
+      result = result * (n - i + 1) / i;
+   }
+
+   result
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   #[test]
+   fn binomial_known_values() {
+      assert_eq!(binomial(49, 6), 13_983_816); // the classic 6-from-49 count
+      assert_eq!(binomial(5, 2), 10);
+      assert_eq!(binomial(49, 0), 1);
+      assert_eq!(binomial(49, 49), 1);
+      assert_eq!(binomial(49, 1), 49);
+      assert_eq!(binomial(6, 9), 0); // k > n
+   }
+
+   #[test]
+   fn odds_for_full_series() {
+      // 6 numbers: C(49,6) * (49-6) = 13_983_816 * 43.
+      let analysis = Analysis::new(6);
+      assert_eq!(analysis.expected_tries(), 13_983_816 * 43);
+      assert!((analysis.probability() - 1.0 / (13_983_816.0 * 43.0)).abs() < 1e-18);
+   }
+
+   #[test]
+   fn odds_for_single_number() {
+      // 1 number: C(49,1) * (49-1) = 49 * 48.
+      assert_eq!(Analysis::new(1).expected_tries(), 49 * 48);
+   }
+}
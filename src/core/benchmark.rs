@@ -0,0 +1,233 @@
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use rand::Rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use super::game::Guess;
+
+// Upper bound on the percentile sample. Trials are streamed, so we keep at most
+// this many draw counts (via reservoir sampling) to estimate percentiles while
+// memory stays independent of the number of trials.
+const RESERVOIR_SIZE: usize = 4096;
+
+/// Monte-Carlo driver: runs a validated [`Guess`] for `num_trials` independent
+/// trials, each played until that trial's guess is matched, and aggregates the
+/// per-trial draws-to-win into a statistical [`Report`].
+pub struct Benchmark {
+   guess: Guess,
+   num_trials: usize,
+}
+
+impl Benchmark {
+   pub fn new(guess: Guess, num_trials: usize) -> Self {
+      Self { guess, num_trials }
+   }
+
+   /// Runs every trial, accumulating results in a single streaming pass so
+   /// memory stays O(histogram buckets + reservoir) regardless of `num_trials`.
+   pub fn run(&self) -> Report {
+      // Seed the reservoir's RNG from the guess's base seed so the whole report
+      // (not just the draws) is reproducible; fall back to entropy otherwise.
+      let rng = match self.guess.base_seed() {
+         Some(base) => StdRng::seed_from_u64(base),
+         None => StdRng::from_entropy(),
+      };
+      let mut acc = Accumulator::new(rng);
+
+      for trial_index in 0..self.num_trials {
+         acc.record(self.guess.run_single_trial(trial_index as u64));
+      }
+
+      acc.into_report()
+   }
+}
+
+/// Streaming aggregator: running mean/variance via Welford's recurrence, a
+/// logarithmic histogram, and a bounded reservoir sample for percentiles.
+struct Accumulator {
+   count: usize,
+   min: usize,
+   max: usize,
+   mean: f64,
+   m2: f64,
+   // histogram[i] counts trials whose draw count falls in [10^i, 10^(i+1)).
+   histogram: Vec<usize>,
+   reservoir: Vec<usize>,
+   // Seeded RNG driving reservoir replacement, so sampling is reproducible and
+   // we avoid a thread-local lookup on every trial.
+   rng: StdRng,
+}
+
+impl Accumulator {
+   fn new(rng: StdRng) -> Self {
+      Self {
+         count: 0,
+         min: usize::MAX,
+         max: 0,
+         mean: 0.0,
+         m2: 0.0,
+         histogram: Vec::new(),
+         reservoir: Vec::with_capacity(RESERVOIR_SIZE),
+         rng,
+      }
+   }
+
+   fn record(&mut self, x: usize) {
+      self.count += 1;
+      self.min = self.min.min(x);
+      self.max = self.max.max(x);
+
+      // Welford: mean += (x - mean) / n; M2 += (x - mean_old) * (x - mean_new).
+      let delta = x as f64 - self.mean;
+      self.mean += delta / self.count as f64;
+      let delta2 = x as f64 - self.mean;
+      self.m2 += delta * delta2;
+
+      self.bucket(x);
+      self.sample(x);
+   }
+
+   fn bucket(&mut self, x: usize) {
+      // Power-of-ten bin. A zero-draw trial can't happen (every trial plays at
+      // least one game), so log10 is always well defined.
+      let bin = (x as f64).log10().floor() as usize;
+      if bin >= self.histogram.len() {
+         self.histogram.resize(bin + 1, 0);
+      }
+      self.histogram[bin] += 1;
+   }
+
+   fn sample(&mut self, x: usize) {
+      // Reservoir sampling: the first RESERVOIR_SIZE trials are kept outright;
+      // later ones replace a random slot with probability RESERVOIR_SIZE / count.
+      if self.reservoir.len() < RESERVOIR_SIZE {
+         self.reservoir.push(x);
+      } else {
+         let slot = self.rng.gen_range(0..self.count);
+         if slot < RESERVOIR_SIZE {
+            self.reservoir[slot] = x;
+         }
+      }
+   }
+
+   fn into_report(mut self) -> Report {
+      let variance = if self.count > 1 {
+         self.m2 / (self.count - 1) as f64
+      } else {
+         0.0
+      };
+
+      self.reservoir.sort_unstable();
+      let percentile = |p: f64| -> usize {
+         if self.reservoir.is_empty() {
+            return 0;
+         }
+         let idx = ((p * self.reservoir.len() as f64).ceil() as usize)
+            .saturating_sub(1)
+            .min(self.reservoir.len() - 1);
+         self.reservoir[idx]
+      };
+
+      Report {
+         count: self.count,
+         min: if self.count == 0 { 0 } else { self.min },
+         max: self.max,
+         mean: self.mean,
+         stddev: variance.sqrt(),
+         median: percentile(0.50),
+         p90: percentile(0.90),
+         p99: percentile(0.99),
+         histogram: self.histogram,
+      }
+   }
+}
+
+/// Aggregated outcome of a [`Benchmark`] run.
+pub struct Report {
+   count: usize,
+   min: usize,
+   max: usize,
+   mean: f64,
+   stddev: f64,
+   median: usize,
+   p90: usize,
+   p99: usize,
+   histogram: Vec<usize>,
+}
+
+impl Display for Report {
+   fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+      writeln!(formatter, "{}", "~".repeat(60))?;
+      writeln!(formatter, "📊 Benchmark over {} trials", self.count)?;
+      writeln!(formatter, "mean:   {:.1} draws", self.mean)?;
+      writeln!(formatter, "stddev: {:.1} draws", self.stddev)?;
+      writeln!(formatter, "median: {} draws", self.median)?;
+      writeln!(formatter, "p90:    {} draws", self.p90)?;
+      writeln!(formatter, "p99:    {} draws", self.p99)?;
+      writeln!(formatter, "min/max: {} / {} draws", self.min, self.max)?;
+      writeln!(formatter, "{}", "~".repeat(60))?;
+
+      let peak = self.histogram.iter().copied().max().unwrap_or(0).max(1);
+      for (bin, &hits) in self.histogram.iter().enumerate() {
+         let bar = "█".repeat(hits * 40 / peak);
+         writeln!(formatter, "10^{:<2} [{:>11}..] {:>8}  {}",
+                  bin, 10usize.pow(bin as u32), hits, bar)?;
+      }
+
+      write!(formatter, "{}", "~".repeat(60))
+   }
+}
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   fn seeded_accumulator() -> Accumulator {
+      Accumulator::new(StdRng::seed_from_u64(0))
+   }
+
+   #[test]
+   fn welford_matches_known_sample() {
+      // Classic sample with mean 5 and sample variance 32/7.
+      let mut acc = seeded_accumulator();
+      for x in [2, 4, 4, 4, 5, 5, 7, 9] {
+         acc.record(x);
+      }
+      let report = acc.into_report();
+
+      assert_eq!(report.count, 8);
+      assert!((report.mean - 5.0).abs() < 1e-9);
+      assert!((report.stddev - (32.0_f64 / 7.0).sqrt()).abs() < 1e-9);
+   }
+
+   #[test]
+   fn percentiles_and_extremes_from_full_sample() {
+      // Fewer trials than the reservoir, so every value is kept verbatim.
+      let mut acc = seeded_accumulator();
+      for x in [2, 4, 4, 4, 5, 5, 7, 9] {
+         acc.record(x);
+      }
+      let report = acc.into_report();
+
+      assert_eq!(report.min, 2);
+      assert_eq!(report.max, 9);
+      assert_eq!(report.median, 4);
+      assert_eq!(report.p90, 9);
+      assert_eq!(report.p99, 9);
+   }
+
+   #[test]
+   fn logarithmic_histogram_buckets_by_power_of_ten() {
+      let mut acc = seeded_accumulator();
+      for x in [3, 7, 42, 99, 500] {
+         acc.record(x);
+      }
+      let report = acc.into_report();
+
+      assert_eq!(report.histogram[0], 2); // 10^0: 3, 7
+      assert_eq!(report.histogram[1], 2); // 10^1: 42, 99
+      assert_eq!(report.histogram[2], 1); // 10^2: 500
+   }
+}
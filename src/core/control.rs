@@ -0,0 +1,79 @@
+use std::io::{stdin, BufRead};
+
+use crossbeam_channel::Sender;
+
+/// Out-of-band commands for a running worker pool.
+///
+/// `Runner` owns the receiving end; a driver (e.g. a stdin thread) holds the
+/// sender and issues commands while a search is in flight. `Pause`/`Resume`/
+/// `Stop` act on the pool as a whole, while `NewGuess` tears the pool down and
+/// respawns it against a freshly validated guess.
+pub enum Control {
+   Pause,
+   Resume,
+   Stop,
+   NewGuess(Vec<u8>, u8),
+}
+
+/// The per-worker signal derived from a [`Control`] command. Workers poll this
+/// at a loop boundary so a half-filled `single_game` buffer is never observed
+/// mid-command: they finish the running draw iteration, then react here.
+#[derive(Clone, Copy)]
+pub enum Signal {
+   Pause,
+   Resume,
+   Stop,
+}
+
+/// Reads control commands from stdin, one per line, and forwards them to a
+/// running pool until end-of-input. Recognised commands:
+///
+/// ```text
+/// pause
+/// resume
+/// stop
+/// new <n, n, ...> | <superzahl>
+/// ```
+///
+/// Spawned by [`Runner::run`] once the initial guess has been read, so it is
+/// the sole reader of stdin while a search is in flight.
+pub fn drive(sender: Sender<Control>) {
+   for line in stdin().lock().lines() {
+      let line = match line {
+         Ok(line) => line,
+         Err(_) => break,
+      };
+
+      match parse_command(&line) {
+         // Stop is terminal: forward it and let the thread exit.
+         Some(Control::Stop) => {
+            sender.send(Control::Stop).ok();
+            break;
+         }
+         // Any other command: stop driving once the pool has gone away.
+         Some(control) => if sender.send(control).is_err() { break; },
+         None => log::warn!("Ignoring unknown control command: {:?}", line.trim()),
+      }
+   }
+}
+
+fn parse_command(line: &str) -> Option<Control> {
+   match line.trim() {
+      "pause" => Some(Control::Pause),
+      "resume" => Some(Control::Resume),
+      "stop" => Some(Control::Stop),
+      rest => rest.strip_prefix("new ").and_then(parse_new_guess),
+   }
+}
+
+fn parse_new_guess(rest: &str) -> Option<Control> {
+   let (series_part, superzahl_part) = rest.split_once('|')?;
+
+   let series = series_part
+      .split(',')
+      .filter_map(|number| number.trim().parse::<u8>().ok())
+      .collect::<Vec<u8>>();
+   let superzahl = superzahl_part.trim().parse::<u8>().ok()?;
+
+   Some(Control::NewGuess(series, superzahl))
+}
@@ -1,131 +1,254 @@
 use std::{thread, time};
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc;
 use std::time::Duration;
 
+use crossbeam_channel::{select, Receiver, Sender};
 use rand::Rng;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
-use super::rules::{SERIES_LENGTH, SERIES_NUMBER_RANGE};
+use super::control::Signal;
+use super::rules::SERIES_NUMBER_RANGE;
 
-pub static HAS_WON: AtomicBool = AtomicBool::new(false);
+// Number of draws a worker plays before it checks the control/shutdown channels
+// again. Polling once per draw would dominate the per-iteration cost; a batch
+// keeps the select! off the hot path while still reacting within a few ms.
+const DRAW_BATCH: usize = 512;
 
 struct Outcome {
-   single_game: [u8; SERIES_LENGTH + 1],
+   single_game: Vec<u8>,
    num_tries: usize,
    last_poll: time::Instant,
+   // The per-thread mutable state owns its RNG so we avoid a thread-local
+   // lookup on every draw and keep the run reproducible for a fixed seed.
+   rng: StdRng,
 }
 
 impl Outcome {
-   fn new() -> Self {
+   fn new(max_pulls: usize, rng: StdRng) -> Self {
       Self {
-         single_game: [0; SERIES_LENGTH + 1],
+         single_game: vec![0; max_pulls],
          num_tries: 0,
          last_poll: time::Instant::now(),
+         rng,
       }
    }
 
    fn extract_single_game_series(&self) -> &[u8] {
-      &self.single_game[..SERIES_LENGTH]
+      &self.single_game[..self.single_game.len() - 1]
    }
 
    fn extract_single_game_superzahl(&self) -> &u8 {
-      &self.single_game[SERIES_LENGTH]
+      &self.single_game[self.single_game.len() - 1]
    }
 
-   fn publish(&mut self, sender: &mpsc::Sender<String>) {
+   fn publish(&mut self, sender: &Sender<String>) {
       let now = time::Instant::now();
       let diff = now - self.last_poll;
 
       if diff > Duration::from_secs(3) {
-         sender.send(format!("{:?} running: {} iterations",
-                             thread::current().id(),
-                             self.num_tries)).unwrap();
+         // The progress channel is bounded; we drop the update rather than block
+         // if the log consumer is behind, so a worker is never stuck on send
+         // while the main thread is busy joining the pool.
+         sender.try_send(format!("{:?} running: {} iterations",
+                                 thread::current().id(),
+                                 self.num_tries)).ok();
          self.last_poll = now;
       }
    }
 }
 
+/// Coordination handles threaded into every worker: the shutdown broadcast
+/// pair, this worker's control signal receiver, and the seed material needed
+/// to reproduce its draws. Bundled into one struct because `Guess` otherwise
+/// grows a constructor argument every time a new piece of pool plumbing is
+/// threaded through it.
+pub struct WorkerContext {
+   pub shutdown_tx: Sender<()>,
+   pub shutdown_rx: Receiver<()>,
+   pub num_workers: usize,
+   // Worker-facing control signal, polled at each batch boundary so Pause never
+   // corrupts a half-filled single_game buffer.
+   pub signal_rx: Receiver<Signal>,
+   // Optional base seed for a reproducible run, plus this worker's index. The
+   // worker derives its own RNG seed as `base_seed ^ thread_index` so that, for
+   // a fixed thread count, the whole run is bit-for-bit reproducible.
+   pub base_seed: Option<u64>,
+   pub thread_index: u64,
+}
+
 #[derive(Clone)]
 pub struct Guess {
    // This struct is expected to be immutable.
-   pub my_series: [u8; SERIES_LENGTH],
+   pub my_series: Vec<u8>,
    pub my_superzahl: u8,
-   pub sender: mpsc::Sender<String>,
+   pub sender: Sender<String>,
+   // Shutdown channel: the winning thread broadcasts "stop" once and every other
+   // worker observes it at the next batch boundary and exits promptly.
+   shutdown_tx: Sender<()>,
+   shutdown_rx: Receiver<()>,
+   num_workers: usize,
+   signal_rx: Receiver<Signal>,
+   base_seed: Option<u64>,
+   thread_index: u64,
 }
 
 impl Guess {
-   pub fn new(series: [u8; SERIES_LENGTH],
-              superzahl: u8,
-              sender: mpsc::Sender<String>) -> Self {
+   pub fn new(series: Vec<u8>, superzahl: u8, sender: Sender<String>, ctx: WorkerContext) -> Self {
       Self {
-         my_series: series, // Example: [1, 45, 38, 5, 23, 19]
+         my_series: series, // Example: vec![1, 45, 38, 5, 23, 19]
          my_superzahl: superzahl,
          sender,
+         shutdown_tx: ctx.shutdown_tx,
+         shutdown_rx: ctx.shutdown_rx,
+         num_workers: ctx.num_workers,
+         signal_rx: ctx.signal_rx,
+         base_seed: ctx.base_seed,
+         thread_index: ctx.thread_index,
+      }
+   }
+
+   /// The base seed of a reproducible run, if any. The `Benchmark` subsystem
+   /// uses it to seed its own auxiliary (reservoir-sampling) RNG so the whole
+   /// report is reproducible, not just the draws.
+   pub fn base_seed(&self) -> Option<u64> {
+      self.base_seed
+   }
+
+   // + 1 designates the Superzahl.
+   fn max_pulls(&self) -> usize {
+      self.my_series.len() + 1
+   }
+
+   // Derives this worker's RNG. `salt` distinguishes otherwise-identical draws
+   // (e.g. per-trial in a benchmark) so a seeded run is varied but reproducible.
+   fn spawn_rng(&self, salt: u64) -> StdRng {
+      match self.base_seed {
+         Some(base) => StdRng::seed_from_u64(base ^ self.thread_index ^ salt),
+         None => StdRng::from_entropy(),
+      }
+   }
+
+   /// Reacts to any pending control signal at a batch boundary. Returns `true`
+   /// when the worker should stop (a `Stop` command, or another worker's win
+   /// while paused); a disconnected signal channel is treated like `Resume` and
+   /// the worker keeps searching. `Pause` blocks here until `Resume`/`Stop` arrives; while paused
+   /// we also watch the shutdown channel so a win in another (still running)
+   /// worker wakes us and tears this one down instead of deadlocking the join.
+   fn handle_signal(&self) -> bool {
+      match self.signal_rx.try_recv() {
+         Ok(Signal::Stop) => true,
+         Ok(Signal::Pause) => loop {
+            select! {
+               recv(self.signal_rx) -> signal => match signal {
+                  Ok(Signal::Resume) | Err(_) => break false,
+                  Ok(Signal::Stop) => break true,
+                  Ok(Signal::Pause) => continue,
+               },
+               // Another worker won while we were paused: stop.
+               recv(self.shutdown_rx) -> _ => break true,
+            }
+         },
+         Ok(Signal::Resume) | Err(_) => false,
       }
    }
 
    pub fn run_games_until_win(&self) -> usize {
-      let mut outcome = Outcome::new();
+      let mut outcome = Outcome::new(self.max_pulls(), self.spawn_rng(0));
+
+      'outer: loop {
+         // Finish the running iteration before reacting to control commands:
+         // we only check here, at a batch boundary, so pausing never corrupts a
+         // half-filled single_game buffer.
+         if self.handle_signal() { break 'outer; }
+
+         // Check the shutdown receiver between batches of draws. We react to
+         // another thread's win here rather than racing on a SeqCst load every
+         // single draw.
+         select! {
+            recv(self.shutdown_rx) -> _ => break 'outer,
+            default => {}
+         }
+
+         for _ in 0..DRAW_BATCH {
+            self.run_single_game(&mut outcome);
+
+            outcome.num_tries += 1;
+            outcome.publish(&self.sender);
+
+            // Check for matching Superzahl first since it's cheap
+            if &self.my_superzahl != outcome.extract_single_game_superzahl() { continue; }
+
+            if self.my_series_contains_all_of(outcome.extract_single_game_series()) {
+               // Player wins!
+               self.on_win(&outcome);
+               break 'outer;
+            }
+         }
+      }
+
+      outcome.num_tries
+   }
+
+   /// Plays games until this guess is matched and returns the number of draws
+   /// it took. Unlike [`run_games_until_win`](Self::run_games_until_win) it
+   /// ignores the channels and never publishes progress; it is the single-trial
+   /// primitive the `Benchmark` subsystem repeats to build a draws-to-win
+   /// distribution. `trial_index` salts the seed so a seeded benchmark plays
+   /// distinct-but-reproducible trials instead of N identical ones.
+   pub fn run_single_trial(&self, trial_index: u64) -> usize {
+      let mut outcome = Outcome::new(self.max_pulls(), self.spawn_rng(trial_index));
 
       loop {
-         if self.has_finished() { break; }
          self.run_single_game(&mut outcome);
-
          outcome.num_tries += 1;
-         outcome.publish(&self.sender);
 
          // Check for matching Superzahl first since it's cheap
          if &self.my_superzahl != outcome.extract_single_game_superzahl() { continue; }
 
          if self.my_series_contains_all_of(outcome.extract_single_game_series()) {
-            // Player wins!
-            self.on_win(&outcome);
+            return outcome.num_tries;
          }
       }
-
-      outcome.num_tries
    }
 
    fn on_win(&self, outcome: &Outcome) {
       // Usually called just one time per guess and only for the thread
       // which solved the game. However, it's not guaranteed - especially
       // if there are less than 5 numbers in the guessed series. In other words,
-      // multiple threads may solve the guess at the same time.
-      self.sender.send("~".repeat(60)).unwrap();
+      // multiple threads may solve the guess at the same time. Extra "stop"
+      // signals simply linger in the unbounded channel and do no harm.
+      self.sender.send("~".repeat(60)).ok();
 
       self.sender.send(format!(
          "🏖 You won! 🍀 {:?} -- Superzahl: {}",
          outcome.extract_single_game_series(),
-         outcome.extract_single_game_superzahl())).unwrap();
+         outcome.extract_single_game_superzahl())).ok();
 
       self.sender.send(format!(
          "🏖 {:?} pulled your guess after {} games.",
-         thread::current().id(), outcome.num_tries)).unwrap();
+         thread::current().id(), outcome.num_tries)).ok();
 
-      self.sender.send("~".repeat(60)).unwrap();
+      self.sender.send("~".repeat(60)).ok();
 
-      HAS_WON.store(true, Ordering::SeqCst);
-   }
-
-   fn has_finished(&self) -> bool {
-      if !HAS_WON.load(Ordering::SeqCst) {
-         return false;
+      // Broadcast "stop" once: one token per worker so every thread waiting at a
+      // batch boundary receives exactly one and tears down.
+      for _ in 0..self.num_workers {
+         self.shutdown_tx.send(()).ok();
       }
-      true
    }
 
    fn run_single_game(&self, result: &mut Outcome) {
       result.single_game.fill_with(Default::default);
 
-      (0..SERIES_LENGTH + 1).for_each({
-         |i| result.single_game[i] = Self::pull_single_number(result)
-      });
+      for i in 0..self.max_pulls() {
+         result.single_game[i] = Self::pull_single_number(result);
+      }
    }
 
    fn pull_single_number(result: &mut Outcome) -> u8 {
-      let pulled_number: u8 = rand::thread_rng().gen_range(SERIES_NUMBER_RANGE);
+      let pulled_number: u8 = result.rng.gen_range(SERIES_NUMBER_RANGE);
 
-      if !&result.single_game.contains(&pulled_number) {
+      if !result.single_game.contains(&pulled_number) {
          pulled_number
       } else {
          Self::pull_single_number(result)
@@ -136,3 +259,78 @@ impl Guess {
       slice.iter().all(|item| self.my_series.contains(item))
    }
 }
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+   use super::Signal;
+   use crossbeam_channel::{bounded, unbounded, Sender};
+
+   // A single-number guess keeps trials cheap (odds ~1/2352) while still
+   // exercising the seeded RNG path.
+   fn seeded_guess(seed: u64) -> Guess {
+      let (sender, _progress_rx) = bounded::<String>(16);
+      let (shutdown_tx, shutdown_rx) = unbounded::<()>();
+      let (_signal_tx, signal_rx) = unbounded();
+      let ctx = WorkerContext {
+         shutdown_tx, shutdown_rx, num_workers: 1, signal_rx, base_seed: Some(seed), thread_index: 0,
+      };
+      Guess::new(vec![7], 13, sender, ctx)
+   }
+
+   // Like `seeded_guess` but retains the signal/shutdown senders so a test can
+   // drive `handle_signal` transitions.
+   fn guess_with_controls() -> (Guess, Sender<Signal>, Sender<()>) {
+      let (sender, _progress_rx) = bounded::<String>(16);
+      let (shutdown_tx, shutdown_rx) = unbounded::<()>();
+      let (signal_tx, signal_rx) = unbounded::<Signal>();
+      let ctx = WorkerContext {
+         shutdown_tx: shutdown_tx.clone(), shutdown_rx, num_workers: 1, signal_rx,
+         base_seed: Some(1), thread_index: 0,
+      };
+      let guess = Guess::new(vec![7], 13, sender, ctx);
+      (guess, signal_tx, shutdown_tx)
+   }
+
+   #[test]
+   fn handle_signal_without_command_continues() {
+      let (guess, _signal_tx, _shutdown_tx) = guess_with_controls();
+      assert!(!guess.handle_signal());
+   }
+
+   #[test]
+   fn handle_signal_stop_stops() {
+      let (guess, signal_tx, _shutdown_tx) = guess_with_controls();
+      signal_tx.send(Signal::Stop).unwrap();
+      assert!(guess.handle_signal());
+   }
+
+   #[test]
+   fn handle_signal_pause_then_resume_continues() {
+      let (guess, signal_tx, _shutdown_tx) = guess_with_controls();
+      signal_tx.send(Signal::Pause).unwrap();
+      signal_tx.send(Signal::Resume).unwrap();
+      assert!(!guess.handle_signal());
+   }
+
+   #[test]
+   fn handle_signal_pause_then_win_stops() {
+      // A paused worker must still wake when another worker broadcasts shutdown.
+      let (guess, signal_tx, shutdown_tx) = guess_with_controls();
+      signal_tx.send(Signal::Pause).unwrap();
+      shutdown_tx.send(()).unwrap();
+      assert!(guess.handle_signal());
+   }
+
+   #[test]
+   fn seeded_single_trial_is_reproducible() {
+      let draws = seeded_guess(42).run_single_trial(3);
+      assert_eq!(draws, seeded_guess(42).run_single_trial(3));
+   }
+
+   #[test]
+   fn distinct_trial_index_varies_the_run() {
+      let guess = seeded_guess(42);
+      assert_ne!(guess.run_single_trial(3), guess.run_single_trial(4));
+   }
+}
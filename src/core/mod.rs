@@ -0,0 +1,6 @@
+pub mod analytic;
+pub mod benchmark;
+pub mod control;
+pub mod game;
+pub mod rules;
+pub mod runner;
@@ -20,13 +20,15 @@ impl std::error::Error for InvalidGuessError {}
 pub struct UserInput {
    series: String,
    superzahl: String,
+   seed: String,
 }
 
 impl UserInput {
-   fn new(series: String, superzahl: String) -> Self {
+   fn new(series: String, superzahl: String, seed: String) -> Self {
       Self {
          series, // Example: "1, 45, 38, 5, 23, 19"
          superzahl, // Example: "13"
+         seed, // Example: "42" (empty means "random")
       }
    }
 
@@ -50,10 +52,17 @@ impl UserInput {
       let mut input_superzahl = String::new();
       stdin().read_line(&mut input_superzahl).unwrap();
 
-      Self::new(input_guess_series, input_superzahl)
+      // 3. User may provide an optional seed for a reproducible run.
+      print!("Enter an optional seed for a reproducible run (leave blank for random): ");
+
+      stdout().flush().unwrap();
+      let mut input_seed = String::new();
+      stdin().read_line(&mut input_seed).unwrap();
+
+      Self::new(input_guess_series, input_superzahl, input_seed)
    }
 
-   pub fn parse(&self) -> Result<(Vec<u8>, u8), InvalidGuessError> {
+   pub fn parse(&self) -> Result<(Vec<u8>, u8, Option<u64>), InvalidGuessError> {
       let parsed_series: Vec<u8> = self.series
          .trim_matches(|c: char| c == ',' || c.is_whitespace())
          .split(',')
@@ -68,10 +77,18 @@ impl UserInput {
          .fold("".to_string(), |acc: String, nxt: &str| acc + nxt)
          .parse().unwrap_or_default();
 
-      log::info!("Your guess: {:?} -- Superzahl: {}", parsed_series, parsed_superzahl);
+      // An empty (or non-numeric) seed means "random"; anything else is parsed
+      // into a fixed base seed for a reproducible run.
+      let parsed_seed: Option<u64> = {
+         let digits: String = self.seed.matches(char::is_numeric).collect();
+         digits.parse().ok()
+      };
+
+      log::info!("Your guess: {:?} -- Superzahl: {} -- Seed: {:?}",
+         parsed_series, parsed_superzahl, parsed_seed);
 
       match Self::validate(&parsed_series, &parsed_superzahl) {
-         Ok(()) => Ok((parsed_series, parsed_superzahl)),
+         Ok(()) => Ok((parsed_series, parsed_superzahl, parsed_seed)),
 
          Err(messages) => {
             for message in &messages {
@@ -83,7 +100,7 @@ impl UserInput {
       }
    }
 
-   fn validate(series: &[u8], superzahl: &u8) -> Result<(), Vec<String>> {
+   pub fn validate(series: &[u8], superzahl: &u8) -> Result<(), Vec<String>> {
       let mut messages = Vec::<String>::new();
 
       if series.is_empty() {
@@ -1,117 +1,273 @@
 use std::{thread, time};
-use std::sync::mpsc;
-use std::sync::mpsc::Receiver;
 use std::thread::JoinHandle;
 
+use crossbeam_channel::{bounded, select, unbounded, Receiver, Sender};
 use log;
 
-use super::game::Guess;
+use super::analytic::Analysis;
+use super::benchmark::Benchmark;
+use super::control::{Control, Signal};
+use super::game::{Guess, WorkerContext};
 use super::rules;
 
+// Capacity of the progress channel. Once this many messages are queued, workers
+// drop further progress updates (see `Outcome::publish`) instead of letting them
+// allocate unbounded Strings; only the win announcement blocks on a full queue.
+const PROGRESS_CAPACITY: usize = 1024;
+
+// Upper bound on the number of independent trials a benchmark plays. Each
+// trial runs to a win, so this is only affordable for short series; a full
+// 6-number guess averages ~6e8 draws/trial, making 100 trials a multi-hour
+// run. `benchmark_trial_count` scales this down using the analytic expected
+// draws per trial so the total draw budget stays tractable.
+const BENCHMARK_TRIALS: usize = 100;
+
+// Total draws a benchmark budgets across all trials, used to scale
+// `BENCHMARK_TRIALS` down for longer (and thus more expensive) series.
+const BENCHMARK_DRAW_BUDGET: u128 = 2_000_000;
+
+// Worker count derived from the host: all logical threads minus one for the
+// main thread, clamped to at least one worker.
+fn worker_count() -> usize {
+   thread::available_parallelism()
+      .map(|n| n.get())
+      .unwrap_or(1)
+      .saturating_sub(1)
+      .max(1)
+}
+
 pub struct Runner {
    num_played_games_until_win: usize,
+   num_workers: usize,
    start_time: time::Instant,
    end_time: time::Instant,
-   receiver: Option<Receiver<String>>,
+   // Optional base seed for a reproducible run. Reproducibility is conditioned
+   // on a fixed worker count, since the winning-thread race determines the
+   // reported totals.
+   base_seed: Option<u64>,
+   // Control channel: a driver holds `control_tx` and issues Pause/Resume/Stop/
+   // NewGuess while a search is in flight; `run` owns the receiving end.
+   control_tx: Sender<Control>,
+   control_rx: Receiver<Control>,
 }
 
 impl Default for Runner {
    fn default() -> Self {
       let now = time::Instant::now();
+      let (control_tx, control_rx) = unbounded::<Control>();
       Self {
          num_played_games_until_win: 0,
+         num_workers: worker_count(),
          start_time: now,
          end_time: now,
-         receiver: None,
+         base_seed: None,
+         control_tx,
+         control_rx,
       }
    }
 }
 
 impl Runner {
-   pub fn run(&mut self) -> Result<(), rules::InvalidGuessError> {
-      // Parse & validate user's guess. Return early on invalid guess.
-      let (series, superzahl) = rules::UserInput::create().parse()?;
+   /// Hands out a sender a driver can use to control a running pool.
+   pub fn controller(&self) -> Sender<Control> {
+      self.control_tx.clone()
+   }
+
+   /// Analytic mode: compute the exact win probability and expected number of
+   /// draws for a validated guess without running any worker threads, so users
+   /// can compare the empirical draws-to-win of [`run`](Self::run) against the
+   /// theoretical expectation.
+   pub fn analyze(&self) -> Result<(), rules::InvalidGuessError> {
+      let (series, _superzahl, _seed) = rules::UserInput::create().parse()?;
+      log::info!("{}", Analysis::new(series.len()));
+
+      Ok(())
+   }
+
+   /// Benchmark mode: play the guess to a win up to `BENCHMARK_TRIALS` times
+   /// and report the empirical distribution of draws-to-win, a companion to
+   /// the theoretical expectation from [`analyze`](Self::analyze). The trial
+   /// count is scaled down for longer series so the run stays tractable; see
+   /// [`benchmark_trial_count`](Self::benchmark_trial_count).
+   pub fn benchmark(&mut self) -> Result<(), rules::InvalidGuessError> {
+      let (series, superzahl, seed) = rules::UserInput::create().parse()?;
+      self.base_seed = seed;
+
+      let expected_tries = Analysis::new(series.len()).expected_tries();
+      let num_trials = Self::benchmark_trial_count(expected_tries);
+      if num_trials < BENCHMARK_TRIALS {
+         log::warn!(
+            "A {}-number guess averages ~{} draws/trial; scaling the benchmark \
+             down to {} trial(s) (from {}) to keep it tractable.",
+            series.len(), expected_tries, num_trials, BENCHMARK_TRIALS);
+      }
 
-      // Create a channel for n:1 thread communication
-      let (sender, receiver) = mpsc::channel();
-      self.receiver = Some(receiver);
+      // Throwaway channels: the benchmark never publishes progress or
+      // coordinates shutdown, it only reuses the Guess draw logic.
+      let (sender, _progress_rx) = bounded::<String>(PROGRESS_CAPACITY);
+      let (shutdown_tx, shutdown_rx) = unbounded::<()>();
+      let (_signal_tx, signal_rx) = unbounded::<Signal>();
+      let ctx = WorkerContext {
+         shutdown_tx, shutdown_rx, num_workers: self.num_workers, signal_rx,
+         base_seed: self.base_seed, thread_index: 0,
+      };
+      let guess = Guess::new(series, superzahl, sender, ctx);
 
-      // Create the main guess game
-      let origin_guess = Guess::new(series, superzahl, sender);
+      log::info!("{}", Benchmark::new(guess, num_trials).run());
 
-      let num_threads = 32; // thread::available_parallelism().unwrap().get();
-      // Drop one for the main thread.
-      log::debug!("START games with {} parallel worker threads.", num_threads-1);
+      Ok(())
+   }
+
+   /// Caps the trial count so the total draw budget (`BENCHMARK_DRAW_BUDGET`)
+   /// is respected: `expected_tries` draws per trial, times however many
+   /// trials fit in the budget, never exceeding `BENCHMARK_TRIALS` and never
+   /// going below one trial.
+   fn benchmark_trial_count(expected_tries: u128) -> usize {
+      let budget_trials = (BENCHMARK_DRAW_BUDGET / expected_tries.max(1)).max(1);
+      (budget_trials as usize).min(BENCHMARK_TRIALS)
+   }
 
-      // Create a vector for thread completion handling
-      let mut joinhandles = vec![];
+   pub fn run(&mut self) -> Result<(), rules::InvalidGuessError> {
+      // Parse & validate user's guess. Return early on invalid guess.
+      let (series, superzahl, seed) = rules::UserInput::create().parse()?;
+      self.base_seed = seed;
+
+      // Now that the guess has been read, start the interactive control driver.
+      // It becomes the sole stdin reader and pumps Pause/Resume/Stop/NewGuess
+      // commands into our control channel while the search runs.
+      let control = self.controller();
+      thread::spawn(move || super::control::drive(control));
 
       // Start timer
       self.start_time = time::Instant::now();
 
-      // Spawn max. available threads minus main thread.
-      for _ in 1..num_threads {
-         let guess = origin_guess.clone();
+      // Spawn the initial pool.
+      let (mut progress_rx, mut signal_txs, mut handles) =
+         self.spawn_pool(series, superzahl);
+      let mut total = 0usize;
 
-         let handle = thread::spawn(move || {
-            // Run games until player wins (or a different thread solved the task).
-            // fn runs until player has won in this or in other threads:
-            guess.run_games_until_win()
-         });
+      // Multiplex worker progress and control commands. We stay here until the
+      // pool either finds a win (progress channel disconnects) or is stopped.
+      loop {
+         select! {
+            recv(progress_rx) -> received => match received {
+               // Emit every received message of the channel, sent by any thread.
+               Ok(msg) => log::info!("{}", msg),
+               // All workers torn down after a win, which closed the channel.
+               Err(_) => break,
+            },
+            recv(self.control_rx) -> command => match command {
+               Ok(Control::Pause) => self.broadcast(&signal_txs, Signal::Pause),
+               Ok(Control::Resume) => self.broadcast(&signal_txs, Signal::Resume),
+               Ok(Control::Stop) => {
+                  self.broadcast(&signal_txs, Signal::Stop);
+                  break;
+               }
+               Ok(Control::NewGuess(new_series, new_superzahl)) => {
+                  match rules::UserInput::validate(&new_series, &new_superzahl) {
+                     Ok(()) => {
+                        // Cleanly tear down the current workers before respawning.
+                        self.broadcast(&signal_txs, Signal::Stop);
+                        total += Self::join_pool(handles);
 
-         joinhandles.push(handle)
+                        let pool = self.spawn_pool(new_series, new_superzahl);
+                        progress_rx = pool.0;
+                        signal_txs = pool.1;
+                        handles = pool.2;
+                     }
+                     Err(messages) => {
+                        for message in messages {
+                           log::error!("{}", message);
+                        }
+                        log::warn!("Keeping the current guess running.");
+                     }
+                  }
+               }
+               // Controller gone: nothing left to drive us, keep serving workers.
+               Err(_) => {}
+            },
+         }
       }
 
-      // Important: Explicitly drop origin_guess.sender instance before calling
-      // receive_and_wait(), otherwise the async channel never gets closed which would
-      // lead to an endless receiver loop there.
-      drop(origin_guess.sender);
-
-      // Signal OS that it may schedule other threads on the CPU instead of this
-      // main thread. Nearly doubles game performance (at least on macOS arm64).
-      thread::yield_now();
-
-      // Stay tuned for worker thread messages
-      self.receive_messages();
+      log::debug!("Search ended. Waiting for worker threads to tear down.");
+      total += Self::join_pool(handles);
+      self.num_played_games_until_win = total;
+      self.end_time = time::Instant::now();
+      log::debug!("All worker threads deallocated.");
 
-      self.collect_results(joinhandles);
       self.print_summary();
 
       Ok(())
    }
 
-   fn receive_messages(&mut self) {
-      // Blocks as long as there is at least 1 active sender.
-      // Guard
-      if self.receiver.is_none() {
-         panic!("Channel-receiver not initialized. Sent Messages from worker \
-         threads can't be evaluated.");
-      }
+   /// Spawns a fresh worker pool against `series`/`superzahl` and returns its
+   /// progress receiver, one signal sender per worker, and the join handles.
+   fn spawn_pool(&self, series: Vec<u8>, superzahl: u8)
+      -> (Receiver<String>, Vec<Sender<Signal>>, Vec<JoinHandle<usize>>) {
+      // Bounded progress channel for n:1 thread communication.
+      let (sender, receiver) = bounded(PROGRESS_CAPACITY);
+      // Unbounded shutdown channel: the winning worker broadcasts "stop" once and
+      // every other worker exits at its next batch boundary.
+      let (shutdown_tx, shutdown_rx) = unbounded::<()>();
+
+      log::debug!("START games with {} parallel worker threads.", self.num_workers);
+
+      let mut signal_txs = Vec::with_capacity(self.num_workers);
+      let mut handles = vec![];
 
-      // Wait for downstream messages of the async mpsc channel.
-      let receiver = self.receiver.as_ref().unwrap();
-      for received in receiver {
-         let msg = received;
-         // Emit every received message of the channel, sent by any thread.
-         log::info!("{}", msg);
+      // Each worker gets its own signal channel rather than a shared receiver
+      // clone: a shared queue lets a worker that's first to recv drain tokens
+      // meant for its peers (fatal for Pause/Resume, which aren't sound unless
+      // every consumer takes exactly its own token). A dedicated channel per
+      // worker makes "one token per worker" actually true.
+      //
+      // Each worker derives its own RNG seed from `base_seed ^ thread_index`, so
+      // the pool stays reproducible for a fixed worker count.
+      for thread_index in 0..self.num_workers {
+         let (signal_tx, signal_rx) = unbounded::<Signal>();
+         signal_txs.push(signal_tx);
+
+         let ctx = WorkerContext {
+            shutdown_tx: shutdown_tx.clone(), shutdown_rx: shutdown_rx.clone(),
+            num_workers: self.num_workers, signal_rx,
+            base_seed: self.base_seed, thread_index: thread_index as u64,
+         };
+         let guess = Guess::new(series.clone(), superzahl, sender.clone(), ctx);
+
+         handles.push(thread::spawn(move || {
+            // Run games until player wins (or a different thread solved the task).
+            guess.run_games_until_win()
+         }));
       }
 
-      log::debug!("mpsc channel closed. Waiting for worker threads to tear down.");
+      // Signal OS that it may schedule other threads on the CPU instead of this
+      // main thread. Nearly doubles game performance (at least on macOS arm64).
+      thread::yield_now();
+
+      (receiver, signal_txs, handles)
+   }
+
+   /// Sends `signal` to every worker's own channel, so each receives exactly
+   /// one token instead of racing peers for tokens on a shared queue.
+   fn broadcast(&self, signal_txs: &[Sender<Signal>], signal: Signal) {
+      for signal_tx in signal_txs {
+         signal_tx.send(signal).ok();
+      }
    }
 
-   fn collect_results(&mut self, handles: Vec<JoinHandle<usize>>) {
+   /// Joins every worker of a pool and returns the number of games they played.
+   fn join_pool(handles: Vec<JoinHandle<usize>>) -> usize {
+      let mut played = 0;
       for handle in handles {
-         let thread_id = &handle.thread().id();
+         let thread_id = handle.thread().id();
 
          // Note: join() is blocking
-         let num_games_per_thread = &handle.join().unwrap();
-         self.num_played_games_until_win += num_games_per_thread;
+         let num_games_per_thread = handle.join().unwrap();
+         played += num_games_per_thread;
 
          log::debug!("{:?} closed. Played {} games.", thread_id, num_games_per_thread);
       }
-
-      self.end_time = time::Instant::now();
-      log::debug!("All worker threads deallocated.");
+      played
    }
 
    fn duration_seconds(&self) -> usize {
@@ -132,3 +288,41 @@ impl Runner {
       log::info!("{}", "~".repeat(60));
    }
 }
+
+#[cfg(test)]
+mod tests {
+   use super::*;
+
+   // Regression test: broadcasting to a shared receiver clone let the first
+   // worker to wake drain every peer's token (see handle_signal's greedy
+   // `Ok(Signal::Pause) => continue` arm). Each worker must have its own
+   // channel so a broadcast delivers exactly one token per worker.
+   #[test]
+   fn broadcast_delivers_exactly_one_signal_per_worker_channel() {
+      let runner = Runner::default();
+      let num_workers = 3;
+      let (signal_txs, signal_rxs): (Vec<_>, Vec<_>) =
+         (0..num_workers).map(|_| unbounded::<Signal>()).unzip();
+
+      runner.broadcast(&signal_txs, Signal::Pause);
+
+      for signal_rx in &signal_rxs {
+         assert!(matches!(signal_rx.try_recv(), Ok(Signal::Pause)));
+         // No leftover token for a peer to greedily drain.
+         assert!(signal_rx.try_recv().is_err());
+      }
+   }
+
+   #[test]
+   fn benchmark_trial_count_stays_full_for_a_cheap_series() {
+      // 1-number guess: ~2352 expected draws/trial, far under the budget.
+      assert_eq!(Runner::benchmark_trial_count(2_352), BENCHMARK_TRIALS);
+   }
+
+   #[test]
+   fn benchmark_trial_count_scales_down_for_an_expensive_series() {
+      // 6-number guess: ~6e8 expected draws/trial would take a single trial
+      // alone past the budget, so it's capped at one trial rather than zero.
+      assert_eq!(Runner::benchmark_trial_count(601_304_088), 1);
+   }
+}
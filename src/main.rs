@@ -1,4 +1,5 @@
 use std::env;
+use std::io::{stdin, stdout, Write};
 
 use crate::core::runner::Runner;
 
@@ -9,6 +10,38 @@ fn main() {
    env_logger::init();
 
    loop {
-      if Runner::default().run().is_ok() { break; }
+      let done = match prompt_mode() {
+         // End-of-input (e.g. piped stdin closed): stop rather than spin on an
+         // empty prompt forever.
+         None => true,
+         Some(Mode::Simulate) => Runner::default().run().is_ok(),
+         Some(Mode::Benchmark) => Runner::default().benchmark().is_ok(),
+         Some(Mode::Analytic) => Runner::default().analyze().is_ok(),
+      };
+
+      if done { break; }
+   }
+}
+
+enum Mode {
+   Simulate,
+   Benchmark,
+   Analytic,
+}
+
+fn prompt_mode() -> Option<Mode> {
+   print!("Select mode — [1] simulate (default), [2] benchmark, [3] exact odds: ");
+   stdout().flush().unwrap();
+
+   let mut input = String::new();
+   // A zero-byte read is EOF, not an empty line: signal the caller to exit.
+   if stdin().read_line(&mut input).unwrap() == 0 {
+      return None;
    }
+
+   Some(match input.trim() {
+      "2" => Mode::Benchmark,
+      "3" => Mode::Analytic,
+      _ => Mode::Simulate,
+   })
 }